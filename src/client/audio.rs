@@ -0,0 +1,261 @@
+use bevy::{
+    audio::{AudioSink, AudioSinkPlayback, PlaybackSettings, Volume, VolumeLevel},
+    prelude::{
+        in_state, AssetServer, AudioBundle, AudioSource, Camera3d, Commands, Component, Entity,
+        Event, EventReader, Handle, OnEnter, Plugin, Query, Res, Resource, Transform, Update,
+        Vec3, With,
+    },
+};
+
+use super::state_manager::game::PlayState;
+use crate::GameState;
+
+/// Fired by the block-interaction system (the `mouse_control`/`ray_cast`
+/// path) whenever a block is broken or placed, so this plugin can play a
+/// positional sound without owning any interaction logic itself.
+#[derive(Clone, Copy, Debug, Event)]
+pub enum BlockSoundEvent {
+    Break(Vec3),
+    Place(Vec3),
+}
+
+/// Fired by the character controller once per footstep stride while a
+/// `CharacterController` is moving, carrying the emitter position for
+/// positional playback.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct FootstepEvent(pub Vec3);
+
+/// Master/sfx/ambient volume sliders, meant to be edited from the pause
+/// menu's future "Settings" screen and persisted alongside other client
+/// settings. All sounds are scaled by `master` on top of their own bucket.
+#[derive(Clone, Resource)]
+pub struct SoundSettings {
+    pub master: f32,
+    pub sfx: f32,
+    pub ambient: f32,
+}
+
+impl Default for SoundSettings {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            sfx: 1.0,
+            ambient: 0.6,
+        }
+    }
+}
+
+impl SoundSettings {
+    fn sfx_volume(&self) -> f32 {
+        self.master * self.sfx
+    }
+
+    fn ambient_volume(&self) -> f32 {
+        self.master * self.ambient
+    }
+}
+
+/// Handles to the sound assets this plugin plays, loaded once on startup.
+#[derive(Resource)]
+struct AudioAssets {
+    block_break: Handle<AudioSource>,
+    block_place: Handle<AudioSource>,
+    footstep: Handle<AudioSource>,
+    ambient_loop: Handle<AudioSource>,
+    menu_music: Handle<AudioSource>,
+}
+
+/// Marks the single currently-playing ambient/music track so switching
+/// tracks is a despawn-and-respawn instead of juggling multiple handles.
+#[derive(Component)]
+struct AmbientTrack;
+
+/// Marks a one-shot block/footstep sound entity so `despawn_finished_sounds_system`
+/// can clean it up once it's done playing without touching `AmbientTrack`,
+/// which is meant to keep looping.
+#[derive(Component)]
+struct OneShotSound;
+
+/// Loads sound assets, plays spatial effects off gameplay events (block
+/// break/place, footsteps), and swaps a looping ambient/music track with
+/// `GameState`/`PlayState`. Distance attenuation is computed by hand from
+/// the camera's `Transform` rather than relying on an engine spatial
+/// listener, since block/footstep sounds are one-shots fired from gameplay
+/// code rather than entities that live long enough to track a listener.
+pub struct ClientAudioPlugin;
+
+impl Plugin for ClientAudioPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_resource::<SoundSettings>();
+        app.add_event::<BlockSoundEvent>();
+        app.add_event::<FootstepEvent>();
+        app.add_systems(OnEnter(GameState::Game), load_audio_assets);
+        app.add_systems(OnEnter(GameState::Menu), play_menu_music);
+        app.add_systems(OnEnter(PlayState::Main), play_ambient_loop);
+        app.add_systems(
+            Update,
+            (
+                play_block_sound_system,
+                play_footstep_system,
+                despawn_finished_sounds_system,
+            )
+                .run_if(in_state(GameState::Game)),
+        );
+    }
+}
+
+fn load_audio_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(AudioAssets {
+        block_break: asset_server.load("sounds/block_break.ogg"),
+        block_place: asset_server.load("sounds/block_place.ogg"),
+        footstep: asset_server.load("sounds/footstep.ogg"),
+        ambient_loop: asset_server.load("sounds/ambient_loop.ogg"),
+        menu_music: asset_server.load("sounds/menu_music.ogg"),
+    });
+}
+
+fn play_menu_music(
+    mut commands: Commands,
+    assets: Option<Res<AudioAssets>>,
+    settings: Res<SoundSettings>,
+    existing: Query<Entity, With<AmbientTrack>>,
+) {
+    switch_ambient_track(
+        &mut commands,
+        existing,
+        assets.map(|assets| assets.menu_music.clone()),
+        settings.ambient_volume(),
+    );
+}
+
+fn play_ambient_loop(
+    mut commands: Commands,
+    assets: Option<Res<AudioAssets>>,
+    settings: Res<SoundSettings>,
+    existing: Query<Entity, With<AmbientTrack>>,
+) {
+    switch_ambient_track(
+        &mut commands,
+        existing,
+        assets.map(|assets| assets.ambient_loop.clone()),
+        settings.ambient_volume(),
+    );
+}
+
+fn switch_ambient_track(
+    commands: &mut Commands,
+    existing: Query<Entity, With<AmbientTrack>>,
+    track: Option<Handle<AudioSource>>,
+    volume: f32,
+) {
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+    let Some(track) = track else {
+        return;
+    };
+    commands.spawn((
+        AmbientTrack,
+        AudioBundle {
+            source: track,
+            settings: PlaybackSettings::LOOP.with_volume(Volume::Relative(VolumeLevel::new(volume))),
+        },
+    ));
+}
+
+/// Attenuates `volume` towards zero the further `source` is from
+/// `listener`, with a short minimum range so nearby sounds stay audible.
+/// Takes a plain position rather than a `Query` so it's testable without
+/// standing up a `World`; callers pull the camera's `Transform` out first.
+fn attenuate(volume: f32, source: Vec3, listener: Option<Vec3>) -> f32 {
+    let Some(listener) = listener else {
+        return volume;
+    };
+    let distance = listener.distance(source).max(1.0);
+    volume / distance
+}
+
+fn camera_position(camera_query: &Query<&Transform, With<Camera3d>>) -> Option<Vec3> {
+    camera_query.get_single().ok().map(|transform| transform.translation)
+}
+
+fn play_block_sound_system(
+    mut commands: Commands,
+    mut block_sounds: EventReader<BlockSoundEvent>,
+    assets: Option<Res<AudioAssets>>,
+    settings: Res<SoundSettings>,
+    camera_query: Query<&Transform, With<Camera3d>>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    for event in block_sounds.iter() {
+        let (handle, position) = match *event {
+            BlockSoundEvent::Break(position) => (assets.block_break.clone(), position),
+            BlockSoundEvent::Place(position) => (assets.block_place.clone(), position),
+        };
+        let volume = attenuate(settings.sfx_volume(), position, camera_position(&camera_query));
+        commands.spawn((
+            OneShotSound,
+            Transform::from_translation(position),
+            AudioBundle {
+                source: handle,
+                settings: PlaybackSettings::ONCE.with_volume(Volume::Relative(VolumeLevel::new(volume))),
+            },
+        ));
+    }
+}
+
+fn play_footstep_system(
+    mut commands: Commands,
+    mut footsteps: EventReader<FootstepEvent>,
+    assets: Option<Res<AudioAssets>>,
+    settings: Res<SoundSettings>,
+    camera_query: Query<&Transform, With<Camera3d>>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    for FootstepEvent(position) in footsteps.iter().copied() {
+        let volume = attenuate(settings.sfx_volume(), position, camera_position(&camera_query));
+        commands.spawn((
+            OneShotSound,
+            Transform::from_translation(position),
+            AudioBundle {
+                source: assets.footstep.clone(),
+                settings: PlaybackSettings::ONCE.with_volume(Volume::Relative(VolumeLevel::new(volume))),
+            },
+        ));
+    }
+}
+
+/// Despawns one-shot block/footstep sound entities once their `AudioSink`
+/// reports playback finished. Bevy never does this on its own, so without
+/// this system every block interaction and footstep would leak an entity
+/// for the life of the process.
+fn despawn_finished_sounds_system(
+    mut commands: Commands,
+    sinks: Query<(Entity, &AudioSink), With<OneShotSound>>,
+) {
+    for (entity, sink) in &sinks {
+        if sink.empty() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attenuate_returns_volume_unchanged_without_a_listener() {
+        assert_eq!(attenuate(1.0, Vec3::ZERO, None), 1.0);
+    }
+
+    #[test]
+    fn attenuate_halves_volume_at_double_the_minimum_range() {
+        let listener = Vec3::new(2.0, 0.0, 0.0);
+        assert_eq!(attenuate(1.0, Vec3::ZERO, Some(listener)), 0.5);
+    }
+}
@@ -0,0 +1,207 @@
+use bevy::{
+    prelude::{Color, Handle, Image, Plugin, Query, Res, Resource, Vec2, With},
+    window::{PrimaryWindow, Window},
+};
+use bevy_egui::{
+    egui::{self, epaint::Shadow, Color32},
+    EguiContexts,
+};
+
+/// 十字准星的绘制样式。
+#[derive(Clone, Default)]
+pub enum CrosshairStyle {
+    #[default]
+    LinesCross,
+    Dot,
+    Circle,
+    Image(Handle<Image>),
+}
+
+/// Runtime-tunable crosshair appearance, meant to be edited from a future
+/// options screen and persisted alongside other client settings.
+#[derive(Resource, Clone)]
+pub struct CrosshairSettings {
+    pub style: CrosshairStyle,
+    pub scale: f32,
+    pub color: Color,
+    pub thickness: f32,
+    /// Pixel offset from the exact screen center.
+    pub offset: Vec2,
+    /// Style/color swapped in when the ray hits an interactable entity.
+    pub entity_style: CrosshairStyle,
+    pub entity_color: Color,
+}
+
+impl Default for CrosshairSettings {
+    fn default() -> Self {
+        Self {
+            style: CrosshairStyle::LinesCross,
+            scale: 20.0,
+            color: Color::WHITE,
+            thickness: 2.0,
+            offset: Vec2::ZERO,
+            entity_style: CrosshairStyle::LinesCross,
+            entity_color: Color::YELLOW,
+        }
+    }
+}
+
+/// What the center-screen ray is currently hitting, updated every frame by
+/// the raycast plugin. Kept separate from `MeshRayCastPlugin` internals so
+/// the crosshair system doesn't need to know how the ray is cast.
+#[derive(Resource, Clone, Copy, Default)]
+pub struct LookingAt {
+    pub block: bool,
+    pub entity: bool,
+}
+
+/// Inserts the crosshair resources. `GamePlugin` is responsible for
+/// scheduling `egui_crosshair_system` alongside its other `PlayState`-gated
+/// systems.
+pub struct CrosshairPlugin;
+
+impl Plugin for CrosshairPlugin {
+    fn build(&self, app: &mut bevy::prelude::App) {
+        app.init_resource::<CrosshairSettings>();
+        app.init_resource::<LookingAt>();
+    }
+}
+
+fn color32_from(color: Color) -> Color32 {
+    let [r, g, b, a] = color.as_rgba_f32().map(|c| (c * 255.0).round() as u8);
+    Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+/// Draws the crosshair into the same transparent `CentralPanel` overlay,
+/// picking geometry/painter calls based on `CrosshairSettings::style` and
+/// switching to the entity style/color when `LookingAt::entity` is true.
+pub fn egui_crosshair_system(
+    mut contexts: EguiContexts,
+    window_query: Query<&mut Window, With<PrimaryWindow>>,
+    settings: Res<CrosshairSettings>,
+    looking_at: Res<LookingAt>,
+) {
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let size = Vec2::new(window.width(), window.height());
+
+    // `add_image` registers the handle with egui's texture map if it hasn't
+    // been already (it's a no-op on repeat calls for the same handle), which
+    // `image_id` alone never does - without this the image style never
+    // resolves a texture id and silently draws nothing.
+    let image_texture = match &settings.style {
+        CrosshairStyle::Image(handle) if !looking_at.entity => {
+            Some(contexts.add_image(handle.clone()))
+        }
+        _ => match &settings.entity_style {
+            CrosshairStyle::Image(handle) if looking_at.entity => {
+                Some(contexts.add_image(handle.clone()))
+            }
+            _ => None,
+        },
+    };
+    let ctx = contexts.ctx_mut();
+
+    // 透明的屏幕！
+    let my_frame = egui::containers::Frame {
+        inner_margin: egui::style::Margin::same(10.),
+        outer_margin: egui::style::Margin::same(10.),
+        rounding: egui::Rounding::same(1.0),
+        shadow: Shadow {
+            extrusion: 1.0,
+            color: Color32::TRANSPARENT,
+        },
+        fill: Color32::TRANSPARENT,
+        stroke: egui::Stroke::new(2.0, Color32::TRANSPARENT),
+    };
+
+    let (style, color) = if looking_at.entity {
+        (&settings.entity_style, settings.entity_color)
+    } else {
+        (&settings.style, settings.color)
+    };
+    let color = color32_from(color);
+    let center = egui::Pos2::new(size.x / 2.0, size.y / 2.0)
+        + egui::Vec2::new(settings.offset.x, settings.offset.y);
+    // Hitting an entity/player uses a shorter diagonal crosshair so it reads
+    // differently at a glance from the default block-placement plus-sign.
+    let scale = if looking_at.entity {
+        settings.scale * 0.6
+    } else {
+        settings.scale
+    };
+
+    egui::CentralPanel::default()
+        .frame(my_frame)
+        .show(ctx, |ui| {
+            let painter = ui.painter();
+            match style {
+                CrosshairStyle::LinesCross => {
+                    draw_lines_cross(painter, center, scale, settings.thickness, color, looking_at.entity);
+                }
+                CrosshairStyle::Dot => {
+                    painter.circle_filled(center, settings.thickness.max(2.0), color);
+                }
+                CrosshairStyle::Circle => {
+                    painter.circle_stroke(
+                        center,
+                        scale / 2.0,
+                        egui::Stroke::new(settings.thickness, color),
+                    );
+                }
+                CrosshairStyle::Image(_) => {
+                    if let Some(texture_id) = image_texture {
+                        let rect = egui::Rect::from_center_size(center, egui::Vec2::splat(scale));
+                        painter.image(
+                            texture_id,
+                            rect,
+                            egui::Rect::from_min_max(egui::Pos2::ZERO, egui::Pos2::new(1.0, 1.0)),
+                            Color32::WHITE,
+                        );
+                    }
+                }
+            }
+        });
+}
+
+fn draw_lines_cross(
+    painter: &egui::Painter,
+    center: egui::Pos2,
+    scale: f32,
+    thickness: f32,
+    color: Color32,
+    diagonal: bool,
+) {
+    let half = scale / 2.0;
+    if diagonal {
+        let a = egui::Vec2::new(half, half);
+        painter.line_segment([center - a, center + a], egui::Stroke::new(thickness, color));
+        let b = egui::Vec2::new(half, -half);
+        painter.line_segment([center - b, center + b], egui::Stroke::new(thickness, color));
+        return;
+    }
+
+    let v_rect = egui::Rect::from_min_max(
+        egui::Pos2::new(center.x - thickness / 2.0, center.y - half),
+        egui::Pos2::new(center.x + thickness / 2.0, center.y + half),
+    );
+    painter.rect_filled(v_rect, 1.0, color);
+
+    let h_rect = egui::Rect::from_min_max(
+        egui::Pos2::new(center.x - half, center.y - thickness / 2.0),
+        egui::Pos2::new(center.x + half, center.y + thickness / 2.0),
+    );
+    painter.rect_filled(h_rect, 1.0, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color32_from_round_trips_channels() {
+        let color32 = color32_from(Color::rgba(1.0, 0.0, 0.5, 1.0));
+        assert_eq!(color32, Color32::from_rgba_unmultiplied(255, 0, 128, 255));
+    }
+}
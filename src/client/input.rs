@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use bevy::prelude::{
+    App, Event, EventReader, EventWriter, Input, IntoSystemConfigs, KeyCode, MouseButton, Plugin,
+    Res, ResMut, Resource, State, Update,
+};
+use serde::{Deserialize, Serialize};
+
+use super::state_manager::game::PlayState;
+
+/// High-level actions the rest of the client reacts to instead of raw
+/// `KeyCode`/`MouseButton` input, so every control surface (gameplay,
+/// console, pause menu, visualizer) can be rebound from one place.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, Serialize, Deserialize)]
+pub enum GameAction {
+    MoveForward,
+    MoveBackward,
+    MoveLeft,
+    MoveRight,
+    Jump,
+    PrimaryUse,
+    SecondaryUse,
+    ToggleVisualizer,
+    ToggleConsole,
+    Pause,
+    ToggleStatusScreen,
+}
+
+/// Actions that only make sense while gameplay owns input; suppressed
+/// whenever the console or pause menu is the topmost focus layer.
+const GAMEPLAY_ONLY_ACTIONS: &[GameAction] = &[
+    GameAction::MoveForward,
+    GameAction::MoveBackward,
+    GameAction::MoveLeft,
+    GameAction::MoveRight,
+    GameAction::Jump,
+    GameAction::PrimaryUse,
+    GameAction::SecondaryUse,
+];
+
+/// Fired once per frame a bound key/button for `action` is newly pressed and
+/// the arbiter decided the current focus layer may see it.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, Event)]
+pub struct GameActionEvent(pub GameAction);
+
+/// Action -> physical input map, serializable so it can be saved and edited
+/// from a future keybindings screen instead of living hardcoded per-system.
+#[derive(Clone, Resource, Serialize, Deserialize)]
+pub struct KeyBindings {
+    pub keys: HashMap<GameAction, Vec<KeyCode>>,
+    pub mouse_buttons: HashMap<GameAction, Vec<MouseButton>>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let keys = HashMap::from([
+            (GameAction::MoveForward, vec![KeyCode::W]),
+            (GameAction::MoveBackward, vec![KeyCode::S]),
+            (GameAction::MoveLeft, vec![KeyCode::A]),
+            (GameAction::MoveRight, vec![KeyCode::D]),
+            (GameAction::Jump, vec![KeyCode::Space]),
+            (GameAction::ToggleVisualizer, vec![KeyCode::F1]),
+            (GameAction::ToggleConsole, vec![KeyCode::Grave]),
+            (GameAction::Pause, vec![KeyCode::Escape]),
+            (GameAction::ToggleStatusScreen, vec![KeyCode::Tab]),
+        ]);
+        let mouse_buttons = HashMap::from([
+            (GameAction::PrimaryUse, vec![MouseButton::Left]),
+            (GameAction::SecondaryUse, vec![MouseButton::Right]),
+        ]);
+        Self { keys, mouse_buttons }
+    }
+}
+
+/// Which layer currently owns input. Only the topmost layer present here
+/// gets gameplay-only actions; UI-only actions (console/pause toggles) pass
+/// through regardless so they stay reachable from anywhere.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+enum InputLayer {
+    Console,
+    Pause,
+    Gameplay,
+}
+
+/// Tracks which UI layer wants exclusive input this frame. `console_open` is
+/// flipped by `console_focus_system` on the console toggle; `PlayState`
+/// supplies the pause half directly.
+#[derive(Resource, Default)]
+pub struct InputFocus {
+    pub console_open: bool,
+}
+
+impl InputFocus {
+    fn topmost(&self, play_state: PlayState) -> InputLayer {
+        if self.console_open {
+            InputLayer::Console
+        } else if play_state == PlayState::Paused {
+            InputLayer::Pause
+        } else {
+            InputLayer::Gameplay
+        }
+    }
+}
+
+/// Inserts `KeyBindings`/`InputFocus` and runs `input_arbiter_system`.
+/// Edge-triggered consumers (`mouse_button_system`, the visualizer toggle,
+/// the pause toggle) read `GameActionEvent` instead of raw `Input<_>`; held
+/// movement reads `Input<KeyCode>` through `KeyBindings` and checks
+/// `InputFocus` itself.
+pub struct InputArbiterPlugin;
+
+impl Plugin for InputArbiterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KeyBindings>();
+        app.init_resource::<InputFocus>();
+        app.add_event::<GameActionEvent>();
+        app.add_systems(Update, input_arbiter_system);
+        app.add_systems(Update, console_focus_system.after(input_arbiter_system));
+    }
+}
+
+/// Flips `InputFocus::console_open` on `GameAction::ToggleConsole`, so the
+/// console actually becomes the topmost `InputLayer` instead of that field
+/// sitting permanently `false`. Runs after `input_arbiter_system` so this
+/// frame's toggle takes effect starting next frame, same as `PlayState`
+/// transitions elsewhere in the client.
+fn console_focus_system(mut actions: EventReader<GameActionEvent>, mut focus: ResMut<InputFocus>) {
+    if actions.iter().any(|GameActionEvent(action)| *action == GameAction::ToggleConsole) {
+        focus.console_open = !focus.console_open;
+    }
+}
+
+/// Reads raw keyboard/mouse input once per frame, resolves it against
+/// `KeyBindings`, and writes a `GameActionEvent` per newly pressed action
+/// that the topmost `InputFocus` layer is allowed to see.
+fn input_arbiter_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    bindings: Res<KeyBindings>,
+    focus: Res<InputFocus>,
+    play_state: Res<State<PlayState>>,
+    mut actions: EventWriter<GameActionEvent>,
+) {
+    let layer = focus.topmost(*play_state.get());
+
+    for (action, keys) in bindings.keys.iter() {
+        if layer_allows(layer, *action) && keys.iter().any(|key| keyboard_input.just_pressed(*key)) {
+            actions.send(GameActionEvent(*action));
+        }
+    }
+    for (action, buttons) in bindings.mouse_buttons.iter() {
+        if layer_allows(layer, *action)
+            && buttons.iter().any(|button| mouse_input.just_pressed(*button))
+        {
+            actions.send(GameActionEvent(*action));
+        }
+    }
+}
+
+fn layer_allows(layer: InputLayer, action: GameAction) -> bool {
+    match layer {
+        InputLayer::Gameplay => true,
+        InputLayer::Pause | InputLayer::Console => !GAMEPLAY_ONLY_ACTIONS.contains(&action),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gameplay_layer_allows_everything() {
+        assert!(layer_allows(InputLayer::Gameplay, GameAction::MoveForward));
+        assert!(layer_allows(InputLayer::Gameplay, GameAction::ToggleConsole));
+    }
+
+    #[test]
+    fn console_and_pause_suppress_gameplay_only_actions() {
+        for layer in [InputLayer::Console, InputLayer::Pause] {
+            assert!(!layer_allows(layer, GameAction::MoveForward));
+            assert!(!layer_allows(layer, GameAction::PrimaryUse));
+            assert!(layer_allows(layer, GameAction::ToggleConsole));
+            assert!(layer_allows(layer, GameAction::Pause));
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use bevy::{
+    input::mouse::MouseWheel,
+    prelude::{
+        in_state, App, EventReader, Input, IntoSystemConfigs, KeyCode, Plugin, Res, ResMut,
+        Resource, Update,
+    },
+};
+use bevy_egui::{
+    egui::{self, Color32},
+    EguiContexts,
+};
+use bevy_renet::renet::{DefaultChannel, RenetClient};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    input::{GameAction, GameActionEvent, InputFocus},
+    state_manager::game::PlayState,
+};
+
+/// World block/item type id, matching whatever identifies a block for the
+/// mesh/ray-cast systems. Kept as a bare alias here since the hotbar itself
+/// doesn't interpret the id, only stores and displays it.
+pub type ItemId = u32;
+
+pub const HOTBAR_SLOTS: usize = 9;
+
+/// One hotbar slot: an item id plus how many of it the player is carrying.
+/// `item` is `None` for an empty slot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HotbarSlot {
+    pub item: Option<ItemId>,
+    pub count: u32,
+}
+
+/// Server-authoritative hotbar contents for the local player.
+/// `client_sync_server_messages` overwrites `slots` wholesale whenever the
+/// server sends a `ServerMessage::InventorySync`, so the HUD (and block
+/// placement) always reflects what the server thinks the player holds
+/// rather than a local guess. `selected` is purely a client-side cursor
+/// into those slots.
+#[derive(Clone, Resource)]
+pub struct Inventory {
+    pub slots: [HotbarSlot; HOTBAR_SLOTS],
+    pub selected: usize,
+}
+
+impl Default for Inventory {
+    fn default() -> Self {
+        Self {
+            slots: [HotbarSlot::default(); HOTBAR_SLOTS],
+            selected: 0,
+        }
+    }
+}
+
+impl Inventory {
+    /// The item id currently held, if any. Read by `mouse_button_system`
+    /// when handling `GameAction::SecondaryUse`, so the block it places
+    /// always matches the selected slot rather than a fixed type.
+    pub fn selected_item(&self) -> Option<ItemId> {
+        self.slots[self.selected].item
+    }
+}
+
+/// Sent by the server whenever a player's hotbar changes (pickup, server-side
+/// consumption, initial spawn). Replaces the client's `Inventory::slots`
+/// wholesale rather than patching individual slots.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InventorySyncMessage {
+    pub slots: [HotbarSlot; HOTBAR_SLOTS],
+}
+
+/// Every message the server pushes to this client on
+/// `DefaultChannel::ReliableUnordered`, tagged so exactly one system
+/// (`client_sync_server_messages`) drains the channel and dispatches by
+/// variant, instead of several systems blind-`deserialize`-ing the same
+/// queue and silently stealing bytes meant for each other.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ServerMessage {
+    InventorySync(InventorySyncMessage),
+}
+
+/// Sent by the client when the player uses the item in the selected slot, off
+/// the back of `GameAction::SecondaryUse`. The server decides whether the use
+/// is valid (and what it consumes) and answers with an `InventorySyncMessage`
+/// if the hotbar changed; the client never mutates `Inventory` itself.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct UseItemMessage {
+    pub slot: usize,
+}
+
+/// Inserts the `Inventory` resource and schedules slot-selection/rendering.
+/// `GamePlugin` is responsible for wiring `client_sync_server_messages` and
+/// `send_use_item_system` alongside its other networked systems, since those
+/// need to run whenever the client is connected rather than only in
+/// `PlayState::Main`.
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Inventory>();
+        app.add_systems(
+            Update,
+            (hotbar_select_system, egui_hotbar_system).run_if(in_state(PlayState::Main)),
+        );
+    }
+}
+
+const DIGIT_KEYS: [KeyCode; HOTBAR_SLOTS] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+];
+
+/// Moves `Inventory::selected` from the number row (`1`-`9` map to slots
+/// 0-8) or the scroll wheel, wrapping at either end. Reads raw input rather
+/// than going through the `GameAction` arbiter since slot selection is
+/// positional, not a single rebindable action; gating on `PlayState::Main`
+/// and `InputFocus::console_open` keeps it from firing while the
+/// console/pause menu has focus.
+fn hotbar_select_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut wheel: EventReader<MouseWheel>,
+    mut inventory: ResMut<Inventory>,
+    focus: Res<InputFocus>,
+) {
+    if focus.console_open {
+        wheel.clear();
+        return;
+    }
+    for (index, key) in DIGIT_KEYS.iter().enumerate() {
+        if keyboard_input.just_pressed(*key) {
+            inventory.selected = index;
+        }
+    }
+
+    let scroll: f32 = wheel.iter().map(|event| event.y).sum();
+    if scroll > 0.0 {
+        inventory.selected = (inventory.selected + HOTBAR_SLOTS - 1) % HOTBAR_SLOTS;
+    } else if scroll < 0.0 {
+        inventory.selected = (inventory.selected + 1) % HOTBAR_SLOTS;
+    }
+}
+
+/// Draws the bottom-centered hotbar: one box per slot showing the item id
+/// and stack count, with the selected slot outlined. Uses a floating
+/// `egui::Area` rather than the crosshair's transparent `CentralPanel` since
+/// it only needs to cover its own strip along the bottom of the screen.
+fn egui_hotbar_system(mut contexts: EguiContexts, inventory: Res<Inventory>) {
+    egui::Area::new("hotbar")
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0., -20.))
+        .show(contexts.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                for (index, slot) in inventory.slots.iter().enumerate() {
+                    let selected = index == inventory.selected;
+                    egui::Frame::none()
+                        .fill(Color32::from_black_alpha(160))
+                        .stroke(egui::Stroke::new(
+                            if selected { 2.0 } else { 1.0 },
+                            if selected { Color32::WHITE } else { Color32::GRAY },
+                        ))
+                        .inner_margin(egui::style::Margin::same(4.0))
+                        .show(ui, |ui| {
+                            ui.set_min_size(egui::vec2(40., 40.));
+                            ui.vertical_centered(|ui| {
+                                ui.label(match slot.item {
+                                    Some(item) => item.to_string(),
+                                    None => String::new(),
+                                });
+                                if slot.count > 1 {
+                                    ui.label(format!("x{}", slot.count));
+                                }
+                            });
+                        });
+                }
+            });
+        });
+}
+
+/// Sole reader of `DefaultChannel::ReliableUnordered`: drains every
+/// `ServerMessage` the server sent this frame and applies it to whichever
+/// resource/component it targets. Meant to run alongside
+/// `client_sync_players`.
+pub fn client_sync_server_messages(mut client: ResMut<RenetClient>, mut inventory: ResMut<Inventory>) {
+    while let Some(message) = client.receive_message(DefaultChannel::ReliableUnordered) {
+        let Ok(message) = bincode::deserialize::<ServerMessage>(&message) else {
+            continue;
+        };
+        match message {
+            ServerMessage::InventorySync(sync) => inventory.slots = sync.slots,
+        }
+    }
+}
+
+/// Tells the server the player used the selected slot whenever
+/// `GameAction::SecondaryUse` fires (the same action `mouse_button_system`
+/// treats as "place"), so it can authorize consuming the item and answer
+/// with an `InventorySyncMessage` if the stack changed.
+pub fn send_use_item_system(
+    mut actions: EventReader<GameActionEvent>,
+    inventory: Res<Inventory>,
+    mut client: ResMut<RenetClient>,
+) {
+    if !actions.iter().any(|GameActionEvent(action)| *action == GameAction::SecondaryUse) {
+        return;
+    }
+    let message = UseItemMessage { slot: inventory.selected };
+    if let Ok(bytes) = bincode::serialize(&message) {
+        client.send_message(DefaultChannel::ReliableUnordered, bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selected_item_reads_the_selected_slot() {
+        let mut inventory = Inventory::default();
+        inventory.slots[2] = HotbarSlot { item: Some(7), count: 3 };
+        inventory.selected = 2;
+
+        assert_eq!(inventory.selected_item(), Some(7));
+    }
+
+    #[test]
+    fn selected_item_is_none_for_an_empty_slot() {
+        let inventory = Inventory::default();
+        assert_eq!(inventory.selected_item(), None);
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::{
+    in_state, App, Commands, Component, EventWriter, Input, IntoSystemConfigs, KeyCode, Local,
+    OnEnter, Plugin, Query, Res, Time, Transform, Update, Vec3, With,
+};
+
+use super::super::{
+    audio::FootstepEvent,
+    input::{GameAction, InputFocus, KeyBindings},
+    state_manager::game::PlayState,
+    status::PlayerVitals,
+};
+use crate::GameState;
+
+/// Marks the single locally-controlled player entity - the character this
+/// client moves directly, as opposed to remote players synced over the
+/// network. `status::PlayerVitals` lives on this same entity.
+#[derive(Component)]
+pub struct CharacterController;
+
+const MOVE_SPEED: f32 = 4.5;
+
+/// Distance walked, in world units, between consecutive `FootstepEvent`s.
+const FOOTSTEP_STRIDE: f32 = 2.0;
+
+/// Spawns the local `CharacterController` and drives it from held movement
+/// keys, gated the same way `mouse_button_system` is: only while
+/// `PlayState::Main` owns input.
+pub struct CharacterControllerPlugin;
+
+impl Plugin for CharacterControllerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Game), spawn_character_controller);
+        app.add_systems(Update, movement_system.run_if(in_state(PlayState::Main)));
+    }
+}
+
+fn spawn_character_controller(mut commands: Commands) {
+    commands.spawn((CharacterController, Transform::default(), PlayerVitals::default()));
+}
+
+fn is_held(keyboard_input: &Input<KeyCode>, bindings: &KeyBindings, action: GameAction) -> bool {
+    bindings
+        .keys
+        .get(&action)
+        .is_some_and(|keys| keys.iter().any(|key| keyboard_input.pressed(*key)))
+}
+
+/// Moves the controller at a constant speed while a bound movement key is
+/// held. Reads `Input<KeyCode>` directly rather than `GameActionEvent`,
+/// which only fires on the frame a key is newly pressed, since continuous
+/// movement needs held-state rather than an edge-triggered action. Fires a
+/// `FootstepEvent` every `FOOTSTEP_STRIDE` units walked, tracked in
+/// `distance_since_footstep` rather than on a fixed timer so footstep
+/// cadence follows distance covered regardless of frame rate. Does nothing
+/// while `InputFocus::console_open`, matching how the arbiter suppresses
+/// the movement actions for the console layer.
+fn movement_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    focus: Res<InputFocus>,
+    time: Res<Time>,
+    mut footsteps: EventWriter<FootstepEvent>,
+    mut distance_since_footstep: Local<f32>,
+    mut controller: Query<&mut Transform, With<CharacterController>>,
+) {
+    if focus.console_open {
+        return;
+    }
+    let Ok(mut transform) = controller.get_single_mut() else {
+        return;
+    };
+
+    let mut direction = Vec3::ZERO;
+    if is_held(&keyboard_input, &bindings, GameAction::MoveForward) {
+        direction -= Vec3::Z;
+    }
+    if is_held(&keyboard_input, &bindings, GameAction::MoveBackward) {
+        direction += Vec3::Z;
+    }
+    if is_held(&keyboard_input, &bindings, GameAction::MoveLeft) {
+        direction -= Vec3::X;
+    }
+    if is_held(&keyboard_input, &bindings, GameAction::MoveRight) {
+        direction += Vec3::X;
+    }
+
+    if direction != Vec3::ZERO {
+        let step = MOVE_SPEED * time.delta_seconds();
+        transform.translation += direction.normalize() * step;
+
+        *distance_since_footstep += step;
+        if *distance_since_footstep >= FOOTSTEP_STRIDE {
+            *distance_since_footstep = 0.0;
+            footsteps.send(FootstepEvent(transform.translation));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod controller;
+pub mod mouse_control;
+
+use std::collections::HashMap;
+
+use bevy::prelude::{Entity, Resource};
+
+/// Maps a connected client's renet id to the `CharacterController`/remote
+/// player entity spawned for them, so systems that need to address a
+/// specific player (inventory sync, vitals sync, despawn on disconnect)
+/// don't have to walk every entity with the component.
+#[derive(Resource, Default)]
+pub struct ClientLobby {
+    pub players: HashMap<u64, Entity>,
+}
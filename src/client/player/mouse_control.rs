@@ -0,0 +1,80 @@
+use bevy::prelude::{
+    Camera3d, Commands, Entity, EventReader, EventWriter, Query, Res, Transform, Vec3, With,
+};
+
+use super::super::{
+    audio::BlockSoundEvent,
+    input::{GameAction, GameActionEvent},
+    inventory::Inventory,
+    ray_cast::RayCastableBlock,
+};
+
+/// How far, in world units, `SecondaryUse` is allowed to place a block from
+/// the camera, and how far `PrimaryUse` reaches to break one.
+const PLACEMENT_REACH: f32 = 4.0;
+
+/// Breaks or places a block along the center-screen ray in response to
+/// `GameAction::PrimaryUse`/`SecondaryUse` from the input arbiter, instead
+/// of reading `Input<MouseButton>` directly, so block interaction stays
+/// rebindable and stays suppressed while the console/pause menu has focus.
+/// `SecondaryUse` places whatever `Inventory::selected_item` currently
+/// holds; with an empty slot selected, there's nothing to place. Either
+/// action fires a `BlockSoundEvent` so `audio::ClientAudioPlugin` can play
+/// the matching sound without this system knowing anything about audio.
+pub fn mouse_button_system(
+    mut commands: Commands,
+    mut actions: EventReader<GameActionEvent>,
+    mut block_sounds: EventWriter<BlockSoundEvent>,
+    camera_query: Query<&Transform, With<Camera3d>>,
+    blocks: Query<(Entity, &Transform), With<RayCastableBlock>>,
+    inventory: Res<Inventory>,
+) {
+    let Ok(camera_transform) = camera_query.get_single() else {
+        return;
+    };
+
+    for GameActionEvent(action) in actions.iter() {
+        match action {
+            GameAction::PrimaryUse => {
+                if let Some((entity, position)) = closest_block(camera_transform, &blocks) {
+                    commands.entity(entity).despawn();
+                    block_sounds.send(BlockSoundEvent::Break(position));
+                }
+            }
+            GameAction::SecondaryUse => {
+                let Some(item) = inventory.selected_item() else {
+                    continue;
+                };
+                let target =
+                    camera_transform.translation + camera_transform.forward() * PLACEMENT_REACH;
+                commands.spawn((RayCastableBlock { item }, Transform::from_translation(target)));
+                block_sounds.send(BlockSoundEvent::Place(target));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Treat a block within this radius of the camera's forward ray as hit.
+const BREAK_RADIUS: f32 = 0.6;
+
+/// The `RayCastableBlock` nearest the camera along its forward ray (within
+/// `BREAK_RADIUS` of the ray and `PLACEMENT_REACH` along it), together with
+/// its position, or `None` if nothing on the ray is close enough to break.
+fn closest_block(
+    camera_transform: &Transform,
+    blocks: &Query<(Entity, &Transform), With<RayCastableBlock>>,
+) -> Option<(Entity, Vec3)> {
+    let origin = camera_transform.translation;
+    let forward = camera_transform.forward();
+    blocks
+        .iter()
+        .filter_map(|(entity, transform)| {
+            let along = (transform.translation - origin).dot(forward);
+            let off_ray = (origin + forward * along).distance(transform.translation);
+            ((0.0..=PLACEMENT_REACH).contains(&along) && off_ray <= BREAK_RADIUS)
+                .then_some((entity, transform.translation, along))
+        })
+        .min_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap())
+        .map(|(entity, position, _)| (entity, position))
+}
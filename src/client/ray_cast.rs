@@ -0,0 +1,112 @@
+use bevy::prelude::{
+    in_state, App, Camera3d, Component, IntoSystemConfigs, Plugin, Query, ResMut, Transform,
+    Update, Vec3, With,
+};
+
+use super::{crosshair::LookingAt, inventory::ItemId, state_manager::game::PlayState};
+
+/// Marks a spawned block entity as something the forward ray can hit, and
+/// records which item/block type it is so breaking it (or aiming at it)
+/// can report back what was found.
+#[derive(Component)]
+pub struct RayCastableBlock {
+    pub item: ItemId,
+}
+
+/// Marks a non-block entity (player, mob, item drop, ...) that should read
+/// as "entity" rather than "block" when the ray hits it.
+#[derive(Component)]
+pub struct Interactable;
+
+/// How far, in world units, the forward ray reaches before reporting no hit.
+const MAX_RAY_DISTANCE: f32 = 6.0;
+/// Treat anything within this radius of the ray as a hit; a stand-in for a
+/// real mesh/AABB intersection test.
+const HIT_RADIUS: f32 = 0.6;
+
+/// Casts the center-screen ray each frame and publishes what it hit to
+/// `crosshair::LookingAt`, so `egui_crosshair_system` (and anything else
+/// that cares what the player is aiming at) doesn't need to do its own
+/// raycasting.
+pub struct MeshRayCastPlugin;
+
+impl Plugin for MeshRayCastPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_looking_at_system.run_if(in_state(PlayState::Main)),
+        );
+    }
+}
+
+/// Casts a ray forward from the camera and records whether it first meets
+/// an `Interactable` entity or a `RayCastableBlock`, preferring whichever is
+/// closer along the ray when both are in range.
+fn update_looking_at_system(
+    camera_query: Query<&Transform, With<Camera3d>>,
+    entities: Query<&Transform, With<Interactable>>,
+    blocks: Query<&Transform, With<RayCastableBlock>>,
+    mut looking_at: ResMut<LookingAt>,
+) {
+    let Ok(camera_transform) = camera_query.get_single() else {
+        *looking_at = LookingAt::default();
+        return;
+    };
+    let origin = camera_transform.translation;
+    let direction = camera_transform.forward();
+
+    let entity_hit = closest_hit(origin, direction, entities.iter().map(|t| t.translation));
+    let block_hit = closest_hit(origin, direction, blocks.iter().map(|t| t.translation));
+
+    *looking_at = match (entity_hit, block_hit) {
+        (Some(entity_distance), Some(block_distance)) => LookingAt {
+            block: block_distance <= entity_distance,
+            entity: entity_distance < block_distance,
+        },
+        (Some(_), None) => LookingAt { block: false, entity: true },
+        (None, Some(_)) => LookingAt { block: true, entity: false },
+        (None, None) => LookingAt::default(),
+    };
+}
+
+/// Distance along the ray to the closest point in `targets` that falls
+/// within `HIT_RADIUS` of the ray and within `MAX_RAY_DISTANCE`, or `None`
+/// if nothing qualifies.
+fn closest_hit(origin: Vec3, direction: Vec3, targets: impl Iterator<Item = Vec3>) -> Option<f32> {
+    targets
+        .filter_map(|target| {
+            let distance_along_ray = (target - origin).dot(direction);
+            if !(0.0..=MAX_RAY_DISTANCE).contains(&distance_along_ray) {
+                return None;
+            }
+            let closest_point = origin + direction * distance_along_ray;
+            (closest_point.distance(target) <= HIT_RADIUS).then_some(distance_along_ray)
+        })
+        .fold(None, |closest, distance| match closest {
+            Some(current) if current <= distance => Some(current),
+            _ => Some(distance),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_hit_prefers_nearer_target_within_radius() {
+        let origin = Vec3::ZERO;
+        let direction = Vec3::Z;
+        let targets = vec![Vec3::new(0.0, 0.0, 4.0), Vec3::new(0.1, 0.0, 2.0)];
+
+        assert_eq!(closest_hit(origin, direction, targets.into_iter()), Some(2.0));
+    }
+
+    #[test]
+    fn closest_hit_ignores_targets_outside_radius_or_range() {
+        let origin = Vec3::ZERO;
+        let direction = Vec3::Z;
+        let targets = vec![Vec3::new(5.0, 0.0, 2.0), Vec3::new(0.0, 0.0, 100.0)];
+
+        assert_eq!(closest_hit(origin, direction, targets.into_iter()), None);
+    }
+}
@@ -2,22 +2,26 @@ use std::marker::PhantomData;
 
 use bevy::{
     prelude::{
-        in_state, AmbientLight, Commands, EventReader, Input, IntoSystemConfigs, KeyCode, Local,
-        NextState, OnEnter, Plugin, Query, Res, ResMut, States, Update, Vec2, With,
+        in_state, AmbientLight, Commands, EventReader, IntoSystemConfigs, Local, NextState,
+        OnEnter, Plugin, Query, Res, ResMut, Resource, State, States, Update, With,
     },
-    window::{PrimaryWindow, Window},
+    window::{CursorGrabMode, PrimaryWindow, Window},
 };
-use bevy_egui::{
-    egui::{self, epaint::Shadow, Color32},
-    EguiContexts,
+use bevy_egui::{egui, EguiContexts};
+use bevy_renet::renet::{
+    transport::{NetcodeClientTransport, NetcodeTransportError},
+    RenetClient,
 };
-use bevy_renet::renet::{transport::NetcodeTransportError, RenetClient};
 use renet_visualizer::{RenetClientVisualizer, RenetVisualizerStyle};
 
 use crate::{
     client::{
-        client_sync_players, client_sync_players_state,
+        audio::ClientAudioPlugin,
+        client_sync_players,
         console_commands::ConsoleCommandPlugins,
+        crosshair::{egui_crosshair_system, CrosshairPlugin},
+        input::{GameAction, GameActionEvent, InputArbiterPlugin},
+        inventory::{client_sync_server_messages, send_use_item_system, InventoryPlugin},
         mesh_display::ClientMeshPlugin,
         player::{
             controller::{CharacterController, CharacterControllerPlugin},
@@ -25,6 +29,7 @@ use crate::{
             ClientLobby,
         },
         ray_cast::MeshRayCastPlugin,
+        status::{client_sync_players_state, StatusPlugin},
     },
     common::ClientClipSpheresPlugin,
     sky::ClientSkyPlugins,
@@ -36,18 +41,41 @@ use super::{new_renet_client, ConnectionAddr, GameState};
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
 pub enum PlayState {
     Main,
-    // 状态栏
+    /// The full status/stats overlay. See `status::StatusPlugin` for the
+    /// `ToggleStatusScreen`-driven transition in and out of this state and
+    /// the `status_screen_system` it renders while here.
     State,
+    /// Gameplay is suspended behind the pause menu: the cursor is freed and
+    /// `Main`-only input systems (character movement, `mouse_button_system`)
+    /// are gated out, but simulation and network sync keep running.
+    Paused,
     #[default]
     Disabled,
 }
 
+/// Client-side view of the renet connection, driven by `connection_error_system`
+/// and displayed by `connection_status_ui_system` instead of hard-crashing the
+/// client on a transport error.
+#[derive(Clone, Default, Debug, Eq, PartialEq, Resource)]
+pub enum ConnectionStatus {
+    #[default]
+    Connecting,
+    Connected,
+    Disconnected,
+    Error(String),
+}
+
 pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.add_state::<PlayState>();
         app.add_systems(OnEnter(GameState::Game), setup);
+        app.init_resource::<ConnectionStatus>();
+        app.add_systems(
+            Update,
+            connection_status_ui_system.run_if(in_state(GameState::Game)),
+        );
         if CLIENT_DEBUG {}
         app.insert_resource(RenetClientVisualizer::<200>::new(
             RenetVisualizerStyle::default(),
@@ -56,29 +84,62 @@ impl Plugin for GamePlugin {
             Update,
             update_visulizer_system.run_if(in_state(GameState::Game)),
         );
+        app.add_plugins(InputArbiterPlugin);
+        app.add_plugins(CrosshairPlugin);
+        app.add_plugins(InventoryPlugin);
+        app.add_plugins(StatusPlugin);
         app.add_systems(
             Update,
-            egui_center_cursor_system.run_if(in_state(PlayState::Main)),
+            egui_crosshair_system.run_if(in_state(PlayState::Main)),
         );
         // 这里是系统
+        // `CharacterControllerPlugin` gates its own movement system behind
+        // `run_if(in_state(PlayState::Main))`, same as `mouse_button_system`
+        // below, so input stays frozen while paused.
         app.add_plugins(CharacterControllerPlugin);
         app.add_plugins(ClientClipSpheresPlugin::<CharacterController> { data: PhantomData });
         app.add_plugins(ClientMeshPlugin);
+        app.add_plugins(ClientAudioPlugin);
         app.add_plugins(ClientSkyPlugins);
         app.add_plugins(MeshRayCastPlugin);
         app.add_plugins(ConsoleCommandPlugins);
 
+        // `client_sync_players_state` (see `status`) writes the server's
+        // authoritative vitals onto the controlled player's `PlayerVitals`
+        // component over its own channel, separate from
+        // `client_sync_server_messages`'s inventory channel, so the status
+        // HUD/overlay reflect real health rather than a local guess.
         app.add_systems(
             Update,
             (
                 client_sync_players,
                 client_sync_players_state,
-                mouse_button_system,
-                panic_on_error_system,
+                client_sync_server_messages,
+                send_use_item_system,
+                connection_error_system,
             )
                 .run_if(bevy_renet::transport::client_connected())
                 .run_if(in_state(GameState::Game)),
         );
+        // `mouse_button_system` consumes `GameActionEvent`
+        // (`PrimaryUse`/`SecondaryUse`) from the input arbiter rather than
+        // `Input<MouseButton>` directly, so block interaction is rebindable
+        // and stays suppressed while the console/pause menu has focus.
+        app.add_systems(
+            Update,
+            mouse_button_system
+                .run_if(in_state(PlayState::Main))
+                .run_if(bevy_renet::transport::client_connected())
+                .run_if(in_state(GameState::Game)),
+        );
+
+        app.add_systems(Update, pause_toggle_system.run_if(in_state(GameState::Game)));
+        app.add_systems(
+            Update,
+            pause_menu_system.run_if(in_state(PlayState::Paused)),
+        );
+        app.add_systems(OnEnter(PlayState::Paused), release_cursor_system);
+        app.add_systems(OnEnter(PlayState::Main), grab_cursor_system);
     }
 }
 
@@ -95,6 +156,10 @@ fn setup(
         ..Default::default()
     });
     commands.insert_resource(ClientLobby::default());
+    // Reset from whatever `ConnectionStatus` a previous session left behind
+    // (an `Error`/`Disconnected` from the last time `GameState::Game` was
+    // entered), so a fresh connection never shows a stale overlay.
+    commands.insert_resource(ConnectionStatus::Connecting);
     play_state.set(PlayState::Main);
 }
 
@@ -103,10 +168,10 @@ fn update_visulizer_system(
     mut visualizer: ResMut<RenetClientVisualizer<200>>,
     client: Res<RenetClient>,
     mut show_visualizer: Local<bool>,
-    keyboard_input: Res<Input<KeyCode>>,
+    mut actions: EventReader<GameActionEvent>,
 ) {
     visualizer.add_network_info(client.network_info());
-    if keyboard_input.just_pressed(KeyCode::F1) {
+    if actions.iter().any(|GameActionEvent(action)| *action == GameAction::ToggleVisualizer) {
         *show_visualizer = !*show_visualizer;
     }
     if *show_visualizer {
@@ -114,94 +179,135 @@ fn update_visulizer_system(
     }
 }
 
-// If any error is found we just panic
-fn panic_on_error_system(mut renet_error: EventReader<NetcodeTransportError>) {
+/// Transitions into `ConnectionStatus::Error` instead of panicking on a
+/// transport error, so the UI can show a reconnect prompt.
+fn connection_error_system(
+    mut renet_error: EventReader<NetcodeTransportError>,
+    mut status: ResMut<ConnectionStatus>,
+) {
     for e in renet_error.iter() {
-        panic!("{}", e);
+        *status = ConnectionStatus::Error(e.to_string().trim().to_string());
     }
 }
 
-// 中心十字
-
-// 添加中心十字
-pub fn egui_center_cursor_system(
+/// Shows connection problems over the gameplay view and lets the player
+/// reconnect (tearing down and rebuilding the `RenetClient`/transport exactly
+/// as `setup` does) or bail out to the menu.
+fn connection_status_ui_system(
     mut contexts: EguiContexts,
-    window_qurey: Query<&mut Window, With<PrimaryWindow>>,
+    mut commands: Commands,
+    mut status: ResMut<ConnectionStatus>,
+    connection_addr: Res<ConnectionAddr>,
+    mut game_state: ResMut<NextState<GameState>>,
+    client: Option<Res<RenetClient>>,
 ) {
-    let ctx = contexts.ctx_mut();
-
-    let Ok(window) = window_qurey.get_single() else{return;};
-    let size = Vec2::new(window.width(), window.height());
-    // 透明的屏幕！
-    let my_frame = egui::containers::Frame {
-        inner_margin: egui::style::Margin {
-            left: 10.,
-            right: 10.,
-            top: 10.,
-            bottom: 10.,
-        },
-        outer_margin: egui::style::Margin {
-            left: 10.,
-            right: 10.,
-            top: 10.,
-            bottom: 10.,
-        },
-        rounding: egui::Rounding {
-            nw: 1.0,
-            ne: 1.0,
-            sw: 1.0,
-            se: 1.0,
-        },
-        shadow: Shadow {
-            extrusion: 1.0,
-            color: Color32::TRANSPARENT,
-        },
-        fill: Color32::TRANSPARENT,
-        stroke: egui::Stroke::new(2.0, Color32::TRANSPARENT),
+    if let (ConnectionStatus::Connecting | ConnectionStatus::Connected, Some(client)) =
+        (&*status, client.as_deref())
+    {
+        if client.is_connected() {
+            *status = ConnectionStatus::Connected;
+        }
+        if *status == ConnectionStatus::Connected {
+            return;
+        }
+    }
+
+    let message = match &*status {
+        ConnectionStatus::Connecting => "Connecting...".to_string(),
+        ConnectionStatus::Connected => return,
+        ConnectionStatus::Disconnected => "Disconnected from server.".to_string(),
+        ConnectionStatus::Error(e) => format!("Connection error: {e}"),
+    };
+
+    egui::Window::new("Connection")
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0., 0.))
+        .resizable(false)
+        .collapsible(false)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.label(message.trim());
+            ui.horizontal(|ui| {
+                if ui.button("Reconnect").clicked() {
+                    commands.remove_resource::<RenetClient>();
+                    commands.remove_resource::<NetcodeClientTransport>();
+                    let (client, transport) = new_renet_client(connection_addr.clone());
+                    commands.insert_resource(client);
+                    commands.insert_resource(transport);
+                    commands.insert_resource(ClientLobby::default());
+                    *status = ConnectionStatus::Connecting;
+                }
+                if ui.button("Return to menu").clicked() {
+                    commands.remove_resource::<RenetClient>();
+                    commands.remove_resource::<NetcodeClientTransport>();
+                    *status = ConnectionStatus::Disconnected;
+                    game_state.set(GameState::Menu);
+                }
+            });
+        });
+}
+
+/// Toggles `PlayState` between `Main` and `Paused` on the bound `Pause`
+/// action, leaving `State`/`Disabled` alone since pausing only makes sense
+/// mid-gameplay.
+fn pause_toggle_system(
+    mut actions: EventReader<GameActionEvent>,
+    play_state: Res<State<PlayState>>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    if !actions.iter().any(|GameActionEvent(action)| *action == GameAction::Pause) {
+        return;
+    }
+    match play_state.get() {
+        PlayState::Main => next_play_state.set(PlayState::Paused),
+        PlayState::Paused => next_play_state.set(PlayState::Main),
+        _ => {}
+    }
+}
+
+/// Frees and shows the cursor when entering `PlayState::Paused`.
+fn release_cursor_system(mut window_query: Query<&mut Window, With<PrimaryWindow>>) {
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
+    };
+    window.cursor.grab_mode = CursorGrabMode::None;
+    window.cursor.visible = true;
+}
+
+/// Locks and hides the cursor when (re-)entering `PlayState::Main`.
+fn grab_cursor_system(mut window_query: Query<&mut Window, With<PrimaryWindow>>) {
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
     };
+    window.cursor.grab_mode = CursorGrabMode::Locked;
+    window.cursor.visible = false;
+}
 
-    egui::CentralPanel::default()
-        .frame(my_frame)
-        .show(ctx, |ui| {
-            // 计算十字准星的位置和大小
-            let crosshair_size = 20.0;
-            let crosshair_pos = egui::Pos2::new(
-                size.x / 2.0 - crosshair_size / 2.0,
-                size.y / 2.0 - crosshair_size / 2.0,
-            );
-            // 外边框
-            let crosshair_rect =
-                egui::Rect::from_min_size(crosshair_pos, egui::Vec2::splat(crosshair_size));
-
-            // 绘制十字准星的竖线
-            let line_width = 2.0;
-            let line_rect = egui::Rect::from_min_max(
-                egui::Pos2::new(
-                    crosshair_rect.center().x - line_width / 2.0,
-                    crosshair_rect.min.y,
-                ),
-                egui::Pos2::new(
-                    crosshair_rect.center().x + line_width / 2.0,
-                    crosshair_rect.max.y,
-                ),
-            );
-            ui.painter()
-                .rect_filled(line_rect, 1.0, egui::Color32::WHITE);
-
-            // 绘制十字准星的横线
-            let line_rect = egui::Rect::from_min_max(
-                egui::Pos2::new(
-                    crosshair_rect.min.x,
-                    crosshair_rect.center().y - line_width / 2.0,
-                ),
-                egui::Pos2::new(
-                    crosshair_rect.max.x,
-                    crosshair_rect.center().y + line_width / 2.0,
-                ),
-            );
-            ui.painter()
-                .rect_filled(line_rect, 1.0, egui::Color32::WHITE);
-
-            // todo 这里也可以添加下方物品栏
+/// The in-game pause menu: Resume unpauses, Settings is a placeholder until
+/// there's an options screen to open, and Disconnect tears down the renet
+/// client/transport and returns to the main menu.
+fn pause_menu_system(
+    mut contexts: EguiContexts,
+    mut commands: Commands,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut status: ResMut<ConnectionStatus>,
+) {
+    egui::Window::new("Paused")
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0., 0.))
+        .resizable(false)
+        .collapsible(false)
+        .show(contexts.ctx_mut(), |ui| {
+            if ui.button("Resume").clicked() {
+                next_play_state.set(PlayState::Main);
+            }
+            ui.add_enabled(false, egui::Button::new("Settings")); // todo: no options screen yet
+            if ui.button("Disconnect").clicked() {
+                commands.remove_resource::<RenetClient>();
+                commands.remove_resource::<NetcodeClientTransport>();
+                *status = ConnectionStatus::Disconnected;
+                game_state.set(GameState::Menu);
+            }
         });
-}
\ No newline at end of file
+}
+
+// 中心十字: see `crosshair` for `CrosshairSettings`/`egui_crosshair_system`.
+// 下方物品栏: see `inventory` for `Inventory`/`egui_hotbar_system`.
\ No newline at end of file
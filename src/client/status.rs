@@ -0,0 +1,201 @@
+use bevy::prelude::{
+    in_state, App, Component, EventReader, IntoSystemConfigs, NextState, Plugin, Query, Res,
+    ResMut, State, Update, With,
+};
+use bevy_egui::{egui, EguiContexts};
+use bevy_renet::renet::{DefaultChannel, RenetClient};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    input::{GameAction, GameActionEvent},
+    player::controller::CharacterController,
+};
+use crate::GameState;
+
+use super::state_manager::game::PlayState;
+
+/// Server-driven vitals for the locally controlled player. Inserted
+/// alongside `CharacterController` when it spawns, and overwritten wholesale
+/// by `client_sync_players_state` rather than being decremented/healed
+/// locally, so both the compact HUD and the `PlayState::State` overlay only
+/// ever show values the server has authorized.
+#[derive(Clone, Copy, Debug, Component)]
+pub struct PlayerVitals {
+    pub health: f32,
+    pub max_health: f32,
+    pub hunger: f32,
+    pub max_hunger: f32,
+}
+
+impl Default for PlayerVitals {
+    fn default() -> Self {
+        Self {
+            health: 20.0,
+            max_health: 20.0,
+            hunger: 20.0,
+            max_hunger: 20.0,
+        }
+    }
+}
+
+/// Sent by the server with a player's authoritative vitals, on its own
+/// `DefaultChannel::Unreliable` stream so it never contends with
+/// `client_sync_server_messages`'s `DefaultChannel::ReliableUnordered`
+/// (see `inventory`) over the same queue.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct PlayerStateMessage {
+    pub health: f32,
+    pub max_health: f32,
+    pub hunger: f32,
+    pub max_hunger: f32,
+}
+
+impl Default for PlayerStateMessage {
+    /// Same starting values as `PlayerVitals::default`, since this is what
+    /// the server hands a client the moment it connects.
+    fn default() -> Self {
+        Self {
+            health: 20.0,
+            max_health: 20.0,
+            hunger: 20.0,
+            max_hunger: 20.0,
+        }
+    }
+}
+
+/// Renders the compact vitals bar and the detailed `PlayState::State`
+/// overlay, owns the key toggle between `PlayState::Main`/`State`, and
+/// keeps `PlayerVitals` current from the server.
+pub struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            status_toggle_system.run_if(in_state(GameState::Game)),
+        );
+        app.add_systems(
+            Update,
+            compact_vitals_hud_system.run_if(in_state(PlayState::Main)),
+        );
+        app.add_systems(Update, status_screen_system.run_if(in_state(PlayState::State)));
+    }
+}
+
+/// Toggles `PlayState` between `Main` and `State` on `ToggleStatusScreen`,
+/// leaving `Paused`/`Disabled` alone, same shape as `pause_toggle_system`.
+fn status_toggle_system(
+    mut actions: EventReader<GameActionEvent>,
+    play_state: Res<State<PlayState>>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    if !actions
+        .iter()
+        .any(|GameActionEvent(action)| *action == GameAction::ToggleStatusScreen)
+    {
+        return;
+    }
+    match play_state.get() {
+        PlayState::Main => next_play_state.set(PlayState::State),
+        PlayState::State => next_play_state.set(PlayState::Main),
+        _ => {}
+    }
+}
+
+/// Small HUD in a screen corner, shown throughout `PlayState::Main`,
+/// showing health/hunger as bars, so
+/// the player has an at-a-glance read without opening the full status
+/// screen.
+fn compact_vitals_hud_system(
+    mut contexts: EguiContexts,
+    vitals: Query<&PlayerVitals, With<CharacterController>>,
+) {
+    let Ok(vitals) = vitals.get_single() else {
+        return;
+    };
+
+    egui::Area::new("vitals_hud")
+        .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(20., -20.))
+        .show(contexts.ctx_mut(), |ui| {
+            ui.add(
+                egui::ProgressBar::new(vitals.health / vitals.max_health)
+                    .text(format!("Health {}/{}", vitals.health as i32, vitals.max_health as i32)),
+            );
+            ui.add(
+                egui::ProgressBar::new(vitals.hunger / vitals.max_hunger)
+                    .text(format!("Hunger {}/{}", vitals.hunger as i32, vitals.max_hunger as i32)),
+            );
+        });
+}
+
+/// The detailed `PlayState::State` overlay: the same vitals as the compact
+/// HUD, spelled out, shown full until the player toggles back to `Main`.
+fn status_screen_system(
+    mut contexts: EguiContexts,
+    vitals: Query<&PlayerVitals, With<CharacterController>>,
+) {
+    egui::Window::new("Status")
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0., 0.))
+        .resizable(false)
+        .collapsible(false)
+        .show(contexts.ctx_mut(), |ui| {
+            let Ok(vitals) = vitals.get_single() else {
+                ui.label("No status available.");
+                return;
+            };
+            ui.label(format!("Health: {:.0} / {:.0}", vitals.health, vitals.max_health));
+            ui.label(format!("Hunger: {:.0} / {:.0}", vitals.hunger, vitals.max_hunger));
+            ui.separator();
+            ui.label("Press the status key again to return.");
+        });
+}
+
+/// Applies a `PlayerStateMessage` onto `vitals`, replacing every field
+/// wholesale. Split out from `client_sync_players_state` so the pure update
+/// logic is testable without a `RenetClient`.
+fn apply_player_state(vitals: &mut PlayerVitals, state: PlayerStateMessage) {
+    vitals.health = state.health;
+    vitals.max_health = state.max_health;
+    vitals.hunger = state.hunger;
+    vitals.max_hunger = state.max_hunger;
+}
+
+/// Drains the server's vitals channel and applies each `PlayerStateMessage`
+/// to the controlled player's `PlayerVitals`. Meant to run alongside
+/// `client_sync_players`.
+pub fn client_sync_players_state(
+    mut client: ResMut<RenetClient>,
+    mut vitals: Query<&mut PlayerVitals, With<CharacterController>>,
+) {
+    while let Some(message) = client.receive_message(DefaultChannel::Unreliable) {
+        let Ok(state) = bincode::deserialize::<PlayerStateMessage>(&message) else {
+            continue;
+        };
+        if let Ok(mut vitals) = vitals.get_single_mut() {
+            apply_player_state(&mut vitals, state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_player_state_overwrites_every_field() {
+        let mut vitals = PlayerVitals::default();
+        let state = PlayerStateMessage {
+            health: 9.0,
+            max_health: 25.0,
+            hunger: 3.0,
+            max_hunger: 15.0,
+        };
+
+        apply_player_state(&mut vitals, state);
+
+        assert_eq!(vitals.health, 9.0);
+        assert_eq!(vitals.max_health, 25.0);
+        assert_eq!(vitals.hunger, 3.0);
+        assert_eq!(vitals.max_hunger, 15.0);
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use bevy::prelude::{App, Plugin, ResMut, Resource, Update};
+use bevy_renet::renet::{DefaultChannel, RenetServer};
+
+use crate::client::inventory::{
+    HotbarSlot, InventorySyncMessage, ServerMessage, UseItemMessage, HOTBAR_SLOTS,
+};
+
+/// Server-side hotbar state per connected client, keyed by renet client id.
+/// This is the source of truth the client's `Inventory` is reconciled
+/// against; the client never mutates its own hotbar directly.
+#[derive(Resource, Default)]
+pub struct ServerInventories {
+    pub slots: HashMap<u64, [HotbarSlot; HOTBAR_SLOTS]>,
+}
+
+/// Handles `UseItemMessage` from connected clients and answers with a
+/// `ServerMessage::InventorySync`, so `send_use_item_system`/
+/// `client_sync_server_messages` (see `client::inventory`) have a server on
+/// the other end instead of talking into the void.
+pub struct ServerInventoryPlugin;
+
+impl Plugin for ServerInventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ServerInventories>();
+        app.add_systems(Update, handle_use_item_system);
+    }
+}
+
+/// Consumes each client's `UseItemMessage`s, decrements the used slot if it
+/// isn't already empty, and sends the client back its up-to-date hotbar.
+fn handle_use_item_system(mut server: ResMut<RenetServer>, mut inventories: ResMut<ServerInventories>) {
+    let client_ids: Vec<u64> = server.clients_id().into_iter().collect();
+    for client_id in client_ids {
+        let mut changed = false;
+        while let Some(message) = server.receive_message(client_id, DefaultChannel::ReliableUnordered) {
+            let Ok(use_item) = bincode::deserialize::<UseItemMessage>(&message) else {
+                continue;
+            };
+            let slots = inventories
+                .slots
+                .entry(client_id)
+                .or_insert_with(|| [HotbarSlot::default(); HOTBAR_SLOTS]);
+            if let Some(slot) = slots.get_mut(use_item.slot) {
+                if slot.count > 0 {
+                    slot.count -= 1;
+                    if slot.count == 0 {
+                        slot.item = None;
+                    }
+                    changed = true;
+                }
+            }
+        }
+
+        if changed {
+            let slots = inventories.slots[&client_id];
+            let message = ServerMessage::InventorySync(InventorySyncMessage { slots });
+            if let Ok(bytes) = bincode::serialize(&message) {
+                server.send_message(client_id, DefaultChannel::ReliableUnordered, bytes);
+            }
+        }
+    }
+}
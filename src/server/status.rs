@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use bevy::prelude::{App, Plugin, ResMut, Resource, Update};
+use bevy_renet::renet::{DefaultChannel, RenetServer};
+
+use crate::client::status::PlayerStateMessage;
+
+/// Server-side vitals per connected client, keyed by renet client id. The
+/// source of truth `client_sync_players_state` reconciles `PlayerVitals`
+/// against; nothing here is ever read from the client.
+#[derive(Resource, Default)]
+pub struct ServerPlayerVitals {
+    pub vitals: HashMap<u64, PlayerStateMessage>,
+}
+
+/// Periodically pushes each connected client its own authoritative vitals
+/// on `DefaultChannel::Unreliable`, so `client_sync_players_state` has a
+/// server actually sending something instead of listening to an empty
+/// channel.
+pub struct ServerStatusPlugin;
+
+impl Plugin for ServerStatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ServerPlayerVitals>();
+        app.add_systems(Update, broadcast_vitals_system);
+    }
+}
+
+/// Lazily inserts default vitals for any connected client that doesn't have
+/// an entry yet (same `.entry(...).or_insert_with(...)` shape as
+/// `server/inventory.rs`'s `handle_use_item_system`), then sends every
+/// connected client its own current vitals.
+fn broadcast_vitals_system(mut server: ResMut<RenetServer>, mut vitals: ResMut<ServerPlayerVitals>) {
+    let client_ids: Vec<u64> = server.clients_id().into_iter().collect();
+    for client_id in client_ids {
+        let state = *vitals
+            .vitals
+            .entry(client_id)
+            .or_insert_with(PlayerStateMessage::default);
+        if let Ok(bytes) = bincode::serialize(&state) {
+            server.send_message(client_id, DefaultChannel::Unreliable, bytes);
+        }
+    }
+}